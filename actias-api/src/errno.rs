@@ -0,0 +1,69 @@
+/// errors that can be returned from a syscall
+///
+/// the error numbers match their Linux equivalents
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Errno {
+    /// EPERM
+    NotPermitted,
+    /// ESRCH
+    NoSuchProcess,
+    /// ECHILD
+    NoChild,
+    /// EAGAIN
+    TryAgain,
+    /// ENOMEM
+    OutOfMemory,
+    /// EACCES
+    PermissionDenied,
+    /// EFAULT
+    BadAddress,
+    /// EINVAL
+    InvalidArgument,
+    /// EPIPE
+    BrokenPipe,
+    /// ENOSYS
+    NotImplemented,
+    /// an error number that this version of the API doesn't know about
+    ///
+    /// this keeps the original number so it can be passed along unchanged, and is never produced by `try_from`
+    Unknown(usize),
+}
+
+impl Errno {
+    /// gets the error number of this error
+    pub fn number(self) -> usize {
+        match self {
+            Self::NotPermitted => 1,
+            Self::NoSuchProcess => 3,
+            Self::NoChild => 10,
+            Self::TryAgain => 11,
+            Self::OutOfMemory => 12,
+            Self::PermissionDenied => 13,
+            Self::BadAddress => 14,
+            Self::InvalidArgument => 22,
+            Self::BrokenPipe => 32,
+            Self::NotImplemented => 38,
+            Self::Unknown(num) => num,
+        }
+    }
+}
+
+impl TryFrom<usize> for Errno {
+    type Error = ();
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::NotPermitted),
+            3 => Ok(Self::NoSuchProcess),
+            10 => Ok(Self::NoChild),
+            11 => Ok(Self::TryAgain),
+            12 => Ok(Self::OutOfMemory),
+            13 => Ok(Self::PermissionDenied),
+            14 => Ok(Self::BadAddress),
+            22 => Ok(Self::InvalidArgument),
+            32 => Ok(Self::BrokenPipe),
+            38 => Ok(Self::NotImplemented),
+            _ => Err(()),
+        }
+    }
+}
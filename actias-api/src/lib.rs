@@ -1,2 +1,6 @@
 #![no_std]
-// TODO
+
+pub mod errno;
+pub mod syscalls;
+
+pub use errno::Errno;
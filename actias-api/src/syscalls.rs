@@ -0,0 +1,108 @@
+use crate::Errno;
+
+/// the largest error number a syscall can return
+///
+/// syscalls return a single usize. errors are returned as their negated error number (so -1 to -MAX_ERRNO once the value is interpreted as signed),
+/// and every other value is a successful result. this way addresses near the top of the address space can still be returned successfully,
+/// since only the last page's worth of values is reserved for errors
+pub const MAX_ERRNO: usize = 4095;
+
+/// encodes the result of a syscall into the raw value returned to the caller
+///
+/// this is the inverse of `decode_result`, so successful values must not be within the range reserved for errors,
+/// and `Errno::Unknown` must hold an error number within that range that doesn't belong to any other variant
+pub fn encode_result(result: Result<usize, Errno>) -> usize {
+    match result {
+        Ok(value) => {
+            debug_assert!(value <= usize::MAX - MAX_ERRNO, "successful syscall result {value:#x} collides with the error range");
+            value
+        }
+        Err(err) => {
+            let num = err.number();
+            debug_assert!(
+                (1..=MAX_ERRNO).contains(&num) && !(matches!(err, Errno::Unknown(_)) && Errno::try_from(num).is_ok()),
+                "{err:?} can't be encoded as a syscall error"
+            );
+            num.wrapping_neg()
+        }
+    }
+}
+
+/// decodes the raw value returned by a syscall into a result
+///
+/// error numbers this version of the API doesn't know about are returned as `Errno::Unknown`
+pub fn decode_result(value: usize) -> Result<usize, Errno> {
+    if value > usize::MAX - MAX_ERRNO {
+        let num = value.wrapping_neg();
+        Err(Errno::try_from(num).unwrap_or(Errno::Unknown(num)))
+    } else {
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_boundaries() {
+        assert_eq!(decode_result(0), Ok(0));
+        assert_eq!(decode_result(usize::MAX - MAX_ERRNO), Ok(usize::MAX - MAX_ERRNO));
+        assert_eq!(decode_result(usize::MAX - MAX_ERRNO + 1), Err(Errno::Unknown(MAX_ERRNO)));
+        assert_eq!(decode_result(usize::MAX), Err(Errno::NotPermitted));
+        assert_eq!(decode_result(200usize.wrapping_neg()), Err(Errno::Unknown(200)));
+    }
+
+    #[test]
+    fn try_from_rejects_unassigned() {
+        assert_eq!(Errno::try_from(0), Err(()));
+        assert_eq!(Errno::try_from(2), Err(()));
+        assert_eq!(Errno::try_from(200), Err(()));
+        assert_eq!(Errno::try_from(MAX_ERRNO), Err(()));
+        assert_eq!(Errno::try_from(22), Ok(Errno::InvalidArgument));
+    }
+
+    #[test]
+    fn errors_round_trip() {
+        let named = [
+            Errno::NotPermitted,
+            Errno::NoSuchProcess,
+            Errno::NoChild,
+            Errno::TryAgain,
+            Errno::OutOfMemory,
+            Errno::PermissionDenied,
+            Errno::BadAddress,
+            Errno::InvalidArgument,
+            Errno::BrokenPipe,
+            Errno::NotImplemented,
+        ];
+        for err in named {
+            assert_eq!(decode_result(encode_result(Err(err))), Err(err));
+        }
+
+        // every other error number is only representable as Errno::Unknown
+        for num in 1..=MAX_ERRNO {
+            let err = Errno::try_from(num).unwrap_or(Errno::Unknown(num));
+            assert_eq!(err.number(), num);
+            assert_eq!(decode_result(encode_result(Err(err))), Err(err));
+        }
+    }
+
+    #[test]
+    fn values_round_trip() {
+        let max = usize::MAX - MAX_ERRNO;
+        let edges = (0..=0x10000).chain(max - 0x10000..=max);
+        let sample = (0..=max).step_by(max / 0xfff1);
+
+        for value in edges.chain(sample) {
+            assert_eq!(decode_result(encode_result(Ok(value))), Ok(value));
+        }
+    }
+
+    #[test]
+    fn error_range_decodes_as_errors() {
+        for num in 1..=MAX_ERRNO {
+            assert!(decode_result(num.wrapping_neg()).is_err(), "-{num} decoded as a successful value");
+        }
+    }
+}
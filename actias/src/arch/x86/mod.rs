@@ -1,10 +1,19 @@
 mod logger;
 
+use core::ptr::addr_of;
 use log::info;
 
 /// the address the kernel is linked at
 pub const LINKED_BASE: usize = 0xe000_0000;
 
+/// the size of a page of memory
+///
+/// this must match the `ALIGN(0x1000)` used in kernel.ld, since the checks in `check_layout` can only catch it going out of sync at boot
+pub const PAGE_SIZE: usize = 0x1000;
+
+const _: () = assert!(PAGE_SIZE.is_power_of_two(), "PAGE_SIZE must be a power of two");
+const _: () = assert!(LINKED_BASE % PAGE_SIZE == 0, "LINKED_BASE must be page aligned");
+
 #[allow(unused)]
 extern "C" {
     /// start of the kernel's code/data/etc.
@@ -20,10 +29,38 @@ extern "C" {
     static stack_end: u8;
 }
 
+/// makes sure the layout kernel.ld produced matches what the rest of the kernel assumes about it
+///
+/// a mismatch here would otherwise show up as memory corruption somewhere far away from the cause, so it's better to panic as early as possible
+fn check_layout() {
+    let (start, end, stack_bottom, stack_top) = unsafe {
+        (
+            addr_of!(kernel_start) as usize,
+            addr_of!(kernel_end) as usize,
+            addr_of!(stack_base) as usize,
+            addr_of!(stack_end) as usize,
+        )
+    };
+
+    assert!(
+        start == LINKED_BASE,
+        "kernel_start ({start:#x}) doesn't match LINKED_BASE ({LINKED_BASE:#x}), is kernel.ld out of sync?"
+    );
+    assert!(
+        end > start && end % PAGE_SIZE == 0,
+        "kernel_end ({end:#x}) isn't a page aligned address above kernel_start ({start:#x})"
+    );
+    assert!(
+        stack_bottom < stack_top && (start..=end).contains(&stack_bottom) && (start..=end).contains(&stack_top),
+        "stack ({stack_bottom:#x} - {stack_top:#x}) doesn't lie within the kernel ({start:#x} - {end:#x})"
+    );
+}
+
 /// ran by boot.S when paging has been successfully initialized
 #[no_mangle]
 extern "C" fn kmain() {
     logger::init().unwrap();
+    check_layout();
     info!("HellOwOrld! :3");
 
     loop {}